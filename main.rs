@@ -1,11 +1,30 @@
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::usize;
+
+use rayon::prelude::*;
 
 // Define a struct to represent a graph
 pub struct Graph {
     edges: HashMap<u32, Vec<u32>>,
+    weighted_edges: HashMap<u32, Vec<(u32, u32)>>,
+}
+
+// Bundle of shortest-path summary statistics for a single source node
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathStats {
+    pub average: f64,
+    pub min: u64,
+    pub max: u64,
+    pub median: u64,
+    pub stddev: f64,
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Graph {
@@ -13,30 +32,36 @@ impl Graph {
     pub fn new() -> Self {
         Graph {
             edges: HashMap::new(),
+            weighted_edges: HashMap::new(),
         }
     }
 
     // Add an edge to the graph
     pub fn add_edge(&mut self, from: u32, to: u32) {
-        self.edges.entry(from).or_insert(Vec::new()).push(to);
+        self.edges.entry(from).or_default().push(to);
+    }
+
+    // Add a weighted edge to the graph, for use with `dijkstra`
+    pub fn add_weighted_edge(&mut self, from: u32, to: u32, weight: u32) {
+        self.weighted_edges.entry(from).or_default().push((to, weight));
     }
 
     // Perform Breadth-First Search to calculate shortest paths from a source node
     fn bfs(&self, source: u32) -> HashMap<u32, usize> {
         let mut visited: HashSet<u32> = HashSet::new();
         let mut distances: HashMap<u32, usize> = HashMap::new();
-        let mut queue: Vec<(u32, usize)> = Vec::new();
+        let mut queue: VecDeque<(u32, usize)> = VecDeque::new();
 
         visited.insert(source);
-        queue.push((source, 0));
+        queue.push_back((source, 0));
 
-        while let Some((node, distance)) = queue.pop() {
+        while let Some((node, distance)) = queue.pop_front() {
             distances.insert(node, distance);
             if let Some(neighbors) = self.edges.get(&node) {
                 for &neighbor in neighbors {
                     if !visited.contains(&neighbor) {
                         visited.insert(neighbor);
-                        queue.push((neighbor, distance + 1));
+                        queue.push_back((neighbor, distance + 1));
                     }
                 }
             }
@@ -45,21 +70,100 @@ impl Graph {
         distances
     }
 
+    // Shared relaxation core for Dijkstra and A*: explores weighted edges in order of
+    // `g_score + heuristic`, stopping early once `target` is finalized (or running to
+    // completion when `target` is `None`). Returns the best-known cost to every finalized node
+    // alongside a predecessor map for path reconstruction.
+    fn relax(
+        &self,
+        source: u32,
+        target: Option<u32>,
+        heuristic: impl Fn(u32) -> u64,
+    ) -> (HashMap<u32, u64>, HashMap<u32, u32>) {
+        let mut g_score: HashMap<u32, u64> = HashMap::new();
+        let mut came_from: HashMap<u32, u32> = HashMap::new();
+        let mut finalized: HashSet<u32> = HashSet::new();
+        let mut open: BinaryHeap<Reverse<(u64, u32)>> = BinaryHeap::new();
+
+        g_score.insert(source, 0);
+        open.push(Reverse((heuristic(source), source)));
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if finalized.contains(&current) {
+                continue;
+            }
+            finalized.insert(current);
+
+            if Some(current) == target {
+                break;
+            }
+
+            let current_g = g_score[&current];
+            if let Some(neighbors) = self.weighted_edges.get(&current) {
+                for &(neighbor, weight) in neighbors {
+                    if finalized.contains(&neighbor) {
+                        continue;
+                    }
+                    let tentative_g = current_g + weight as u64;
+                    if tentative_g < *g_score.get(&neighbor).unwrap_or(&u64::MAX) {
+                        g_score.insert(neighbor, tentative_g);
+                        came_from.insert(neighbor, current);
+                        open.push(Reverse((tentative_g + heuristic(neighbor), neighbor)));
+                    }
+                }
+            }
+        }
+
+        (g_score, came_from)
+    }
+
+    // Perform Dijkstra's algorithm to calculate shortest weighted path lengths from a source node
+    pub fn dijkstra(&self, source: u32) -> HashMap<u32, u64> {
+        self.relax(source, None, |_| 0).0
+    }
+
+    // Goal-directed search over weighted edges using a pluggable heuristic. Passing the constant
+    // zero heuristic degenerates this to Dijkstra, since both share `relax` as their relaxation
+    // core.
+    pub fn astar(
+        &self,
+        source: u32,
+        target: u32,
+        heuristic: impl Fn(u32) -> u64,
+    ) -> Option<(u64, Vec<u32>)> {
+        let (g_score, came_from) = self.relax(source, Some(target), heuristic);
+        g_score
+            .get(&target)
+            .map(|&cost| (cost, Self::reconstruct_path(&came_from, source, target)))
+    }
+
+    // Shortest-path distances from a source node, over unit-weight BFS or weighted Dijkstra
+    fn distances(&self, source: u32, weighted: bool) -> HashMap<u32, u64> {
+        if weighted {
+            self.dijkstra(source)
+        } else {
+            self.bfs(source)
+                .into_iter()
+                .map(|(node, distance)| (node, distance as u64))
+                .collect()
+        }
+    }
+
     // Calculate the average shortest path length from a source node to all other nodes
-    pub fn average_shortest_path_length(&self, source: u32) -> f64 {
-        let distances = self.bfs(source);
-        let num_nodes = self.edges.len() as f64;
+    pub fn average_shortest_path_length(&self, source: u32, weighted: bool) -> f64 {
+        let distances = self.distances(source, weighted);
+        let num_nodes = distances.len() as f64;
 
-        let total_distance: usize = distances.values().sum();
+        let total_distance: u64 = distances.values().sum();
         total_distance as f64 / num_nodes
     }
 
     // Calculate the standard deviation of the average shortest path lengths from a source node to all other nodes
-    pub fn standard_deviation(&self, source: u32) -> f64 {
-        let distances = self.bfs(source);
-        let num_nodes = self.edges.len() as f64;
+    pub fn standard_deviation(&self, source: u32, weighted: bool) -> f64 {
+        let distances = self.distances(source, weighted);
+        let num_nodes = distances.len() as f64;
 
-        let average_shortest_path = self.average_shortest_path_length(source);
+        let average_shortest_path = self.average_shortest_path_length(source, weighted);
 
         let sum_of_squared_differences = distances.values().fold(0.0, |acc, &distance| {
             acc + (distance as f64 - average_shortest_path).powi(2)
@@ -69,23 +173,23 @@ impl Graph {
     }
 
     // Calculate the maximum shortest path length from a source node to all other nodes
-    pub fn max_shortest_path_length(&self, source: u32) -> usize {
-        let distances = self.bfs(source);
-        *distances.values().max().unwrap_or(&usize::MAX)
+    pub fn max_shortest_path_length(&self, source: u32, weighted: bool) -> u64 {
+        let distances = self.distances(source, weighted);
+        *distances.values().max().unwrap_or(&u64::MAX)
     }
 
     // Calculate the minimum shortest path length from a source node to all other nodes
-    pub fn min_shortest_path_length(&self, source: u32) -> usize {
-        let distances = self.bfs(source);
-        *distances.values().min().unwrap_or(&usize::MAX)
+    pub fn min_shortest_path_length(&self, source: u32, weighted: bool) -> u64 {
+        let distances = self.distances(source, weighted);
+        *distances.values().min().unwrap_or(&u64::MAX)
     }
 
     // Calculate the median shortest path length from a source node to all other nodes
-    pub fn median_shortest_path_length(&self, source: u32) -> usize {
-        let mut distances: Vec<usize> = self.bfs(source).values().cloned().collect();
+    pub fn median_shortest_path_length(&self, source: u32, weighted: bool) -> u64 {
+        let mut distances: Vec<u64> = self.distances(source, weighted).values().cloned().collect();
         distances.sort();
         let n = distances.len();
-        if n % 2 == 0 {
+        if n.is_multiple_of(2) {
             (distances[n / 2 - 1] + distances[n / 2]) / 2
         } else {
             distances[n / 2]
@@ -103,6 +207,354 @@ impl Graph {
 
         distribution
     }
+
+    // Collect the full set of node IDs that appear in the graph, either as a source or a target
+    fn nodes(&self) -> HashSet<u32> {
+        let mut nodes: HashSet<u32> = self.edges.keys().copied().collect();
+        for neighbors in self.edges.values() {
+            nodes.extend(neighbors.iter().copied());
+        }
+        nodes
+    }
+
+    // Calculate betweenness centrality for every node using Brandes' algorithm. `undirected`
+    // must reflect how the edges were added: set it only when every edge was also added in
+    // reverse to model an undirected graph, so each shortest path isn't double-counted twice.
+    // The data this crate targets (Amazon0302) is loaded as directed, so callers there should
+    // pass `false`.
+    pub fn betweenness_centrality(&self, normalized: bool, undirected: bool) -> HashMap<u32, f64> {
+        let nodes = self.nodes();
+        let mut centrality: HashMap<u32, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+
+        for &s in &nodes {
+            let mut stack: Vec<u32> = Vec::new();
+            let mut predecessors: HashMap<u32, Vec<u32>> = HashMap::new();
+            let mut sigma: HashMap<u32, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+            let mut dist: HashMap<u32, i64> = nodes.iter().map(|&n| (n, -1)).collect();
+
+            sigma.insert(s, 1.0);
+            dist.insert(s, 0);
+
+            let mut queue: VecDeque<u32> = VecDeque::new();
+            queue.push_back(s);
+
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                if let Some(neighbors) = self.edges.get(&v) {
+                    for &w in neighbors {
+                        // First time we've reached w: record its distance and queue it
+                        if dist[&w] < 0 {
+                            dist.insert(w, dist[&v] + 1);
+                            queue.push_back(w);
+                        }
+                        // w is reached via a shortest path through v: accumulate path counts
+                        if dist[&w] == dist[&v] + 1 {
+                            let sigma_v = sigma[&v];
+                            *sigma.get_mut(&w).unwrap() += sigma_v;
+                            predecessors.entry(w).or_default().push(v);
+                        }
+                    }
+                }
+            }
+
+            let mut delta: HashMap<u32, f64> = nodes.iter().map(|&n| (n, 0.0)).collect();
+            while let Some(w) = stack.pop() {
+                if let Some(preds) = predecessors.get(&w) {
+                    for &v in preds {
+                        let contribution = (sigma[&v] / sigma[&w]) * (1.0 + delta[&w]);
+                        *delta.get_mut(&v).unwrap() += contribution;
+                    }
+                }
+                if w != s {
+                    *centrality.get_mut(&w).unwrap() += delta[&w];
+                }
+            }
+        }
+
+        // Only halve when the graph is genuinely undirected (edges added both ways), since then
+        // each shortest path between a pair is counted once from either endpoint's perspective
+        if undirected {
+            for value in centrality.values_mut() {
+                *value /= 2.0;
+            }
+        }
+
+        if normalized {
+            let n = nodes.len() as f64;
+            if n > 2.0 {
+                let scale = 1.0 / ((n - 1.0) * (n - 2.0));
+                for value in centrality.values_mut() {
+                    *value *= scale;
+                }
+            }
+        }
+
+        centrality
+    }
+
+    // Reconstruct one shortest path from source to target, or None if target is unreachable
+    pub fn shortest_path(&self, source: u32, target: u32) -> Option<Vec<u32>> {
+        if source == target {
+            return Some(vec![source]);
+        }
+
+        let mut visited: HashSet<u32> = HashSet::new();
+        let mut came_from: HashMap<u32, u32> = HashMap::new();
+        let mut queue: VecDeque<u32> = VecDeque::new();
+
+        visited.insert(source);
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            if let Some(neighbors) = self.edges.get(&node) {
+                for &neighbor in neighbors {
+                    if !visited.contains(&neighbor) {
+                        visited.insert(neighbor);
+                        came_from.insert(neighbor, node);
+                        if neighbor == target {
+                            return Some(Self::reconstruct_path(&came_from, source, target));
+                        }
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    // Walk a single-predecessor map back from target to source
+    fn reconstruct_path(came_from: &HashMap<u32, u32>, source: u32, target: u32) -> Vec<u32> {
+        let mut path = vec![target];
+        let mut current = target;
+        while current != source {
+            current = came_from[&current];
+            path.push(current);
+        }
+        path.reverse();
+        path
+    }
+
+    // Enumerate every distinct shortest path from source to target
+    pub fn all_shortest_paths(&self, source: u32, target: u32) -> Vec<Vec<u32>> {
+        if source == target {
+            return vec![vec![source]];
+        }
+
+        let mut dist: HashMap<u32, usize> = HashMap::new();
+        let mut preds: HashMap<u32, Vec<u32>> = HashMap::new();
+        let mut queue: VecDeque<u32> = VecDeque::new();
+
+        dist.insert(source, 0);
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            if let Some(neighbors) = self.edges.get(&node) {
+                for &neighbor in neighbors {
+                    match dist.get(&neighbor) {
+                        None => {
+                            dist.insert(neighbor, dist[&node] + 1);
+                            preds.entry(neighbor).or_default().push(node);
+                            queue.push_back(neighbor);
+                        }
+                        Some(&d) if d == dist[&node] + 1 => {
+                            preds.entry(neighbor).or_default().push(node);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if !dist.contains_key(&target) {
+            return Vec::new();
+        }
+
+        let mut paths = Vec::new();
+        let mut partial = vec![target];
+        Self::expand_paths(&preds, source, target, &mut partial, &mut paths);
+        paths
+    }
+
+    // Recursively expand a path backwards from `node` through `preds` until `source` is reached
+    fn expand_paths(
+        preds: &HashMap<u32, Vec<u32>>,
+        source: u32,
+        node: u32,
+        partial: &mut Vec<u32>,
+        paths: &mut Vec<Vec<u32>>,
+    ) {
+        if node == source {
+            let mut path = partial.clone();
+            path.reverse();
+            paths.push(path);
+            return;
+        }
+
+        if let Some(predecessors) = preds.get(&node) {
+            for &prev in predecessors {
+                partial.push(prev);
+                Self::expand_paths(preds, source, prev, partial, paths);
+                partial.pop();
+            }
+        }
+    }
+
+    // Summarize a single source's shortest-path distances into a PathStats bundle
+    fn path_stats_from_distances(distances: &HashMap<u32, u64>) -> PathStats {
+        let num_nodes = distances.len() as f64;
+        let total_distance: u64 = distances.values().sum();
+        let average = total_distance as f64 / num_nodes;
+
+        let sum_of_squared_differences = distances.values().fold(0.0, |acc, &distance| {
+            acc + (distance as f64 - average).powi(2)
+        });
+        let stddev = (sum_of_squared_differences / num_nodes).sqrt();
+
+        let mut sorted: Vec<u64> = distances.values().cloned().collect();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        PathStats {
+            average,
+            min: *sorted.first().unwrap_or(&u64::MAX),
+            max: *sorted.last().unwrap_or(&u64::MAX),
+            median,
+            stddev,
+        }
+    }
+
+    // Calculate shortest-path summary statistics from every node, over unit-weight BFS or
+    // weighted Dijkstra, fanning out across cores with rayon once the graph has at least
+    // `parallel_threshold` nodes (smaller graphs run sequentially to avoid paying thread overhead
+    // for no benefit)
+    pub fn all_pairs_path_stats(
+        &self,
+        parallel_threshold: usize,
+        weighted: bool,
+    ) -> HashMap<u32, PathStats> {
+        let nodes = if weighted { self.weighted_nodes() } else { self.nodes() };
+        let sources: Vec<u32> = nodes.into_iter().collect();
+
+        if sources.len() < parallel_threshold {
+            sources
+                .into_iter()
+                .map(|source| {
+                    (
+                        source,
+                        Self::path_stats_from_distances(&self.distances(source, weighted)),
+                    )
+                })
+                .collect()
+        } else {
+            sources
+                .into_par_iter()
+                .map(|source| {
+                    (
+                        source,
+                        Self::path_stats_from_distances(&self.distances(source, weighted)),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    // Collect the full set of node IDs that appear in the weighted edge set
+    fn weighted_nodes(&self) -> HashSet<u32> {
+        let mut nodes: HashSet<u32> = self.weighted_edges.keys().copied().collect();
+        for neighbors in self.weighted_edges.values() {
+            nodes.extend(neighbors.iter().map(|&(to, _)| to));
+        }
+        nodes
+    }
+
+    // Find the minimum-weight route visiting every node exactly once
+    pub fn shortest_hamiltonian_path(&self) -> Option<(u64, Vec<u32>)> {
+        self.hamiltonian_search(true)
+    }
+
+    // Find the maximum-weight route visiting every node exactly once
+    pub fn longest_hamiltonian_path(&self) -> Option<(u64, Vec<u32>)> {
+        self.hamiltonian_search(false)
+    }
+
+    // Backtracking search over every starting node for an extremal Hamiltonian tour
+    fn hamiltonian_search(&self, minimize: bool) -> Option<(u64, Vec<u32>)> {
+        let nodes = self.weighted_nodes();
+        if nodes.is_empty() {
+            return None;
+        }
+
+        let mut state = HamiltonianSearchState {
+            target_len: nodes.len(),
+            minimize,
+            visited: HashSet::new(),
+            path: Vec::new(),
+            best: None,
+        };
+
+        for &start in &nodes {
+            state.visited.clear();
+            state.visited.insert(start);
+            state.path.clear();
+            state.path.push(start);
+            self.extend_hamiltonian_path(&nodes, 0, &mut state);
+        }
+
+        state.best
+    }
+
+    // Extend a partial Hamiltonian path one node at a time, pruning minimization branches whose
+    // running cost already exceeds the best complete tour found so far
+    fn extend_hamiltonian_path(&self, nodes: &HashSet<u32>, cost: u64, state: &mut HamiltonianSearchState) {
+        if state.minimize {
+            if let Some((best_cost, _)) = &state.best {
+                if cost >= *best_cost {
+                    return;
+                }
+            }
+        }
+
+        if state.path.len() == state.target_len {
+            let is_better = match &state.best {
+                None => true,
+                Some((best_cost, _)) if state.minimize => cost < *best_cost,
+                Some((best_cost, _)) => cost > *best_cost,
+            };
+            if is_better {
+                state.best = Some((cost, state.path.clone()));
+            }
+            return;
+        }
+
+        let current = *state.path.last().unwrap();
+        if let Some(neighbors) = self.weighted_edges.get(&current) {
+            for &(next, weight) in neighbors {
+                if !nodes.contains(&next) || state.visited.contains(&next) {
+                    continue;
+                }
+                state.visited.insert(next);
+                state.path.push(next);
+                self.extend_hamiltonian_path(nodes, cost + weight as u64, state);
+                state.path.pop();
+                state.visited.remove(&next);
+            }
+        }
+    }
+}
+
+// Mutable search state threaded through the Hamiltonian backtracking search
+struct HamiltonianSearchState {
+    target_len: usize,
+    minimize: bool,
+    visited: HashSet<u32>,
+    path: Vec<u32>,
+    best: Option<(u64, Vec<u32>)>,
 }
 
 fn main() {
@@ -125,23 +577,23 @@ fn main() {
     let source_node = 0;
 
     // Calculate average shortest path length
-    let average_shortest_path = graph.average_shortest_path_length(source_node);
+    let average_shortest_path = graph.average_shortest_path_length(source_node, false);
     println!("Average Shortest Path Length from Node {}: {:.2}", source_node, average_shortest_path);
 
     // Calculate standard deviation of average shortest path lengths
-    let standard_deviation = graph.standard_deviation(source_node);
+    let standard_deviation = graph.standard_deviation(source_node, false);
     println!("Standard Deviation of Average Shortest Path Lengths from Node {}: {:.2}", source_node, standard_deviation);
 
     // Calculate maximum shortest path length
-    let max_shortest_path = graph.max_shortest_path_length(source_node);
+    let max_shortest_path = graph.max_shortest_path_length(source_node, false);
     println!("Maximum Shortest Path Length from Node {}: {}", source_node, max_shortest_path);
 
     // Calculate minimum shortest path length
-    let min_shortest_path = graph.min_shortest_path_length(source_node);
+    let min_shortest_path = graph.min_shortest_path_length(source_node, false);
     println!("Minimum Shortest Path Length from Node {}: {}", source_node, min_shortest_path);
 
     // Calculate median shortest path length
-    let median_shortest_path = graph.median_shortest_path_length(source_node);
+    let median_shortest_path = graph.median_shortest_path_length(source_node, false);
     println!("Median Shortest Path Length from Node {}: {}", source_node, median_shortest_path);
 
     // Calculate shortest path length distribution
@@ -156,3 +608,205 @@ fn main() {
         println!("Distance {}: {}", distance, count);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A diamond where the direct edge is longer than the two-hop detour, so Dijkstra must relax
+    // past the first-seen distance instead of keeping it
+    #[test]
+    fn dijkstra_prefers_the_cheaper_multi_hop_route() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 5);
+        graph.add_weighted_edge(0, 2, 1);
+        graph.add_weighted_edge(2, 1, 1);
+        graph.add_weighted_edge(1, 3, 1);
+
+        let distances = graph.dijkstra(0);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&1], 2);
+        assert_eq!(distances[&2], 1);
+        assert_eq!(distances[&3], 3);
+    }
+
+    #[test]
+    fn dijkstra_leaves_unreachable_nodes_out_of_the_map() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(2, 3, 1);
+
+        let distances = graph.dijkstra(0);
+        assert!(!distances.contains_key(&3));
+    }
+
+    // A weighted-only chain (built solely with add_weighted_edge, no add_edge calls) must not
+    // divide by `self.edges.len()`, which is 0 for such a graph
+    #[test]
+    fn average_and_stddev_work_on_a_weighted_only_graph() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(1, 2, 3);
+
+        let average = graph.average_shortest_path_length(0, true);
+        assert_eq!(average, (0.0 + 1.0 + 4.0) / 3.0);
+        assert!(average.is_finite());
+
+        let stddev = graph.standard_deviation(0, true);
+        assert!(stddev.is_finite());
+        assert!(stddev > 0.0);
+    }
+
+    // Directed path 0->1->2: node 1 lies on the only shortest path between 0 and 2, so its
+    // betweenness is the textbook value of 1.0, not 0.5 (that would come from halving a count
+    // that was never doubled in the first place)
+    #[test]
+    fn betweenness_centrality_directed_path_is_not_halved() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+
+        let centrality = graph.betweenness_centrality(false, false);
+        assert_eq!(centrality[&1], 1.0);
+        assert_eq!(centrality[&0], 0.0);
+        assert_eq!(centrality[&2], 0.0);
+    }
+
+    // The same path modeled as undirected (edges added both ways): now every pair's shortest
+    // path is discovered once from each endpoint, so node 1 must be halved back down to 1.0
+    #[test]
+    fn betweenness_centrality_undirected_path_is_halved() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 0);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 1);
+
+        let centrality = graph.betweenness_centrality(false, true);
+        assert_eq!(centrality[&1], 1.0);
+    }
+
+    // Normalized directed betweenness on a 3-node path should match 1/((n-1)(n-2)) = 1/2
+    #[test]
+    fn betweenness_centrality_directed_normalized_matches_formula() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+
+        let centrality = graph.betweenness_centrality(true, false);
+        assert_eq!(centrality[&1], 0.5);
+    }
+
+    #[test]
+    fn shortest_path_reconstructs_one_minimal_route() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+
+        let path = graph.shortest_path(0, 3).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&3));
+    }
+
+    #[test]
+    fn shortest_path_returns_none_when_unreachable() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 3);
+
+        assert_eq!(graph.shortest_path(0, 3), None);
+    }
+
+    // Diamond 0->{1,2}->3: both length-2 routes are shortest, so both must be enumerated
+    #[test]
+    fn all_shortest_paths_enumerates_every_tied_route() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+
+        let mut paths = graph.all_shortest_paths(0, 3);
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    }
+
+    fn path_graph() -> Graph {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph
+    }
+
+    // A high threshold forces the sequential branch; a threshold of 0 forces the rayon branch.
+    // Both must agree, since parallelizing is purely a perf knob.
+    #[test]
+    fn all_pairs_path_stats_agree_across_sequential_and_parallel_branches() {
+        let graph = path_graph();
+
+        let sequential = graph.all_pairs_path_stats(usize::MAX, false);
+        let parallel = graph.all_pairs_path_stats(0, false);
+
+        assert_eq!(sequential.len(), 3);
+        for node in [0u32, 1, 2] {
+            assert_eq!(sequential[&node], parallel[&node]);
+        }
+        assert_eq!(sequential[&0].max, 2);
+        assert_eq!(sequential[&0].min, 0);
+        assert_eq!(sequential[&1].average, 0.5);
+    }
+
+    // Zero heuristic degenerates A* to Dijkstra: must still find the cheaper two-hop route
+    #[test]
+    fn astar_with_zero_heuristic_matches_dijkstra() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 5);
+        graph.add_weighted_edge(0, 2, 1);
+        graph.add_weighted_edge(2, 1, 1);
+
+        let (cost, path) = graph.astar(0, 1, |_| 0).unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(path, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn astar_returns_none_when_unreachable() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(2, 3, 1);
+
+        assert_eq!(graph.astar(0, 3, |_| 0), None);
+    }
+
+    // Triangle with a cheap loop (0-1-2) and an expensive direct edge (0-2): the shortest tour
+    // must avoid the expensive edge, the longest tour must use it
+    fn triangle_graph() -> Graph {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 1);
+        graph.add_weighted_edge(1, 0, 1);
+        graph.add_weighted_edge(1, 2, 1);
+        graph.add_weighted_edge(2, 1, 1);
+        graph.add_weighted_edge(0, 2, 10);
+        graph.add_weighted_edge(2, 0, 10);
+        graph
+    }
+
+    #[test]
+    fn shortest_hamiltonian_path_avoids_the_expensive_edge() {
+        let graph = triangle_graph();
+        let (cost, path) = graph.shortest_hamiltonian_path().unwrap();
+        assert_eq!(cost, 2);
+        assert_eq!(path.len(), 3);
+    }
+
+    #[test]
+    fn longest_hamiltonian_path_uses_the_expensive_edge() {
+        let graph = triangle_graph();
+        let (cost, path) = graph.longest_hamiltonian_path().unwrap();
+        assert_eq!(cost, 11);
+        assert_eq!(path.len(), 3);
+    }
+}